@@ -1,9 +1,24 @@
-//! Contains [Block], [Ownership] and implementations
+//! Contains [Block], [Ownership], [Transfer] and implementations
 
-use crate::{error::Error, Hash, Result};
+use crate::{error::Error, Hash, HashAlg, Result};
+#[cfg(feature = "serde")]
+use openssl::pkey::Id;
 use openssl::pkey::{PKey, Private, Public};
 #[cfg(feature = "serde")]
-use serde::{ser::SerializeStruct, Serialize};
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    ser::SerializeTupleStruct,
+    Deserialize, Deserializer, Serialize,
+};
+#[cfg(feature = "serde")]
+use std::fmt;
+#[cfg(feature = "pow")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Protocol version emitted alongside a serialized [Block], bumped whenever the
+/// on-wire/on-disk format changes incompatibly.
+#[cfg(feature = "serde")]
+const PROTO_VERSION: u8 = 1;
 
 /// Single block within a larger blockchain, providing access to a block of data
 ///
@@ -13,6 +28,8 @@ use serde::{ser::SerializeStruct, Serialize};
 ///
 /// - Create a genesis block: [Block::default]
 /// - Create a block containing data: [Block::new]
+/// - Create a block with a chosen hash algorithm: [Block::new_with]
+/// - Create a block with a custom data size limit: [Block::new_bounded]
 /// - Verify a block: [Block::verify]
 ///
 /// # Example
@@ -43,11 +60,27 @@ pub struct Block {
     pub ownership: Ownership,
     /// Signature which wraps data into a key to verify ownership.
     pub signature: [u8; Hash::SIG_LEN],
+    /// Unix timestamp (seconds) this block was mined at. Zero for blocks created
+    /// with [Block::new] rather than [Block::mine].
+    #[cfg(feature = "pow")]
+    pub timestamp: i64,
+    /// Minimum number of leading zero bits this block's hash was mined to satisfy.
+    /// Zero means this block isn't subject to proof-of-work.
+    #[cfg(feature = "pow")]
+    pub difficulty: usize,
+    /// Winning nonce found while mining this block.
+    #[cfg(feature = "pow")]
+    pub nonce: u64,
     /// Underlying data contained within this block.
     pub data: Vec<u8>,
 }
 
 impl<'a> Block {
+    /// Default maximum size, in bytes, a block's `data` may be. Construction fails
+    /// with [Error::DataTooLarge] if exceeded; use [Block::new_bounded] to pick a
+    /// different limit.
+    pub const MAX_DATA_LEN: usize = 1_048_576;
+
     /// Creates a new block from the previous block in a chain alongside the data
     /// contained within this block.
     ///
@@ -67,12 +100,137 @@ impl<'a> Block {
     /// }
     /// ```
     pub fn new(previous_hash: impl Into<&'a Hash>, data: impl Into<Vec<u8>>) -> Result<Self> {
+        Self::new_with(previous_hash, data, HashAlg::default())
+    }
+
+    /// Like [Block::new], but lets the caller pick which [HashAlg] digests the
+    /// block.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use onft::{Block, HashAlg};
+    ///
+    /// fn main() -> onft::Result<()> {
+    ///     let genesis_block = Block::default();
+    ///
+    ///     let data = "Hello, world!";
+    ///     let block = Block::new_with(&genesis_block.hash, data, HashAlg::Sha256)?;
+    ///
+    ///     println!("Block:\n{:?}", block);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new_with(
+        previous_hash: impl Into<&'a Hash>,
+        data: impl Into<Vec<u8>>,
+        alg: HashAlg,
+    ) -> Result<Self> {
+        Self::new_checked(previous_hash, data.into(), alg, Self::MAX_DATA_LEN)
+    }
+
+    /// Like [Block::new], but lets the caller pick a maximum `data` length instead
+    /// of the default [Block::MAX_DATA_LEN].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use onft::Block;
+    ///
+    /// fn main() -> onft::Result<()> {
+    ///     let genesis_block = Block::default();
+    ///
+    ///     let data = "Hello, world!";
+    ///     let block = Block::new_bounded(&genesis_block.hash, data, 1024)?;
+    ///
+    ///     println!("Block:\n{:?}", block);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new_bounded(
+        previous_hash: impl Into<&'a Hash>,
+        data: impl Into<Vec<u8>>,
+        max_len: usize,
+    ) -> Result<Self> {
+        Self::new_checked(previous_hash, data.into(), HashAlg::default(), max_len)
+    }
+
+    /// Shared implementation behind [Block::new], [Block::new_with] and
+    /// [Block::new_bounded]: rejects `data` over `max_len` before hashing it.
+    fn new_checked(
+        previous_hash: impl Into<&'a Hash>,
+        data: Vec<u8>,
+        alg: HashAlg,
+        max_len: usize,
+    ) -> Result<Self> {
+        if data.len() > max_len {
+            return Err(Error::DataTooLarge {
+                len: data.len(),
+                max: max_len,
+            });
+        }
+
+        let (hash, signature, pkey) = Hash::new_with(previous_hash, data.as_slice(), alg)?;
+        Ok(Self {
+            hash,
+            ownership: pkey.into(),
+            signature,
+            #[cfg(feature = "pow")]
+            timestamp: 0,
+            #[cfg(feature = "pow")]
+            difficulty: 0,
+            #[cfg(feature = "pow")]
+            nonce: 0,
+            data,
+        })
+    }
+
+    /// Mines a new block on top of `previous_hash`, searching for a `nonce` such
+    /// that the block's hash has at least `difficulty` leading zero bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use onft::Block;
+    ///
+    /// fn main() -> onft::Result<()> {
+    ///     let genesis_block = Block::default();
+    ///
+    ///     let data = "Hello, world!";
+    ///     let block = Block::mine(&genesis_block.hash, data, 8)?;
+    ///
+    ///     println!("Block:\n{:?}", block);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "pow")]
+    pub fn mine(
+        previous_hash: impl Into<&'a Hash>,
+        data: impl Into<Vec<u8>>,
+        difficulty: usize,
+    ) -> Result<Self> {
+        let previous_hash = previous_hash.into();
         let data = data.into();
-        let (hash, signature, pkey) = Hash::new(previous_hash, data.as_slice())?;
+        if data.len() > Self::MAX_DATA_LEN {
+            return Err(Error::DataTooLarge {
+                len: data.len(),
+                max: Self::MAX_DATA_LEN,
+            });
+        }
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system clock is before the unix epoch")
+            .as_secs() as i64;
+
+        let (hash, signature, nonce, pkey) =
+            Hash::mine(previous_hash, timestamp, data.as_slice(), difficulty)?;
         Ok(Self {
             hash,
             ownership: pkey.into(),
             signature,
+            timestamp,
+            difficulty,
+            nonce,
             data,
         })
     }
@@ -99,6 +257,7 @@ impl<'a> Block {
     ///     Ok(())
     /// }
     /// ```
+    #[cfg(not(feature = "pow"))]
     pub fn verify(&self, previous_hash: impl Into<&'a Hash>) -> Result<bool> {
         let previous_hash = previous_hash.into();
         let data = self.data.as_slice();
@@ -109,6 +268,122 @@ impl<'a> Block {
             Ownership::Genesis => Err(Error::GenesisIsNotKey),
         }
     }
+
+    /// Verifies this individual block based upon the known hash of the last block.
+    ///
+    /// If this block was [mined](Block::mine) (`difficulty` is non-zero), the
+    /// proof-of-work digest and difficulty target are checked on top of the usual
+    /// signature/ownership verification.
+    #[cfg(feature = "pow")]
+    pub fn verify(&self, previous_hash: impl Into<&'a Hash>) -> Result<bool> {
+        let previous_hash = previous_hash.into();
+        let data = self.data.as_slice();
+
+        if self.difficulty == 0 {
+            return match &self.ownership {
+                Ownership::Them(pkey) => {
+                    self.hash.verify(previous_hash, self.signature, data, pkey)
+                }
+                Ownership::Us(pkey) => self.hash.verify(previous_hash, self.signature, data, pkey),
+                Ownership::Genesis => Err(Error::GenesisIsNotKey),
+            };
+        }
+
+        match &self.ownership {
+            Ownership::Them(pkey) => self.hash.verify_pow(
+                previous_hash,
+                self.timestamp,
+                data,
+                self.nonce,
+                self.difficulty,
+                self.signature,
+                pkey,
+            ),
+            Ownership::Us(pkey) => self.hash.verify_pow(
+                previous_hash,
+                self.timestamp,
+                data,
+                self.nonce,
+                self.difficulty,
+                self.signature,
+                pkey,
+            ),
+            Ownership::Genesis => Err(Error::GenesisIsNotKey),
+        }
+    }
+
+    /// Signs a hand-over of this block to `new_owner` with the `current_owner`
+    /// private key.
+    ///
+    /// The key is not checked against this block's current [Ownership] here;
+    /// [Block::apply_transfer] rejects a transfer whose signature doesn't validate
+    /// under the current owner.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use onft::{Block, Ownership};
+    /// use openssl::pkey::{Id, PKey};
+    ///
+    /// fn main() -> onft::Result<()> {
+    ///     let genesis_block = Block::default();
+    ///     let block = Block::new(&genesis_block.hash, "Hello, world!")?;
+    ///
+    ///     let new_owner_key = PKey::generate_ed25519().unwrap();
+    ///     let new_owner =
+    ///         PKey::public_key_from_raw_bytes(&new_owner_key.raw_public_key().unwrap(), Id::ED25519)
+    ///             .unwrap();
+    ///     let current_owner = match &block.ownership {
+    ///         Ownership::Us(pkey) => pkey,
+    ///         _ => unreachable!(),
+    ///     };
+    ///
+    ///     let transfer = block.transfer(current_owner, &new_owner)?;
+    ///     println!("Transfer:\n{:?}", transfer);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn transfer(
+        &self,
+        current_owner: &PKey<Private>,
+        new_owner: &PKey<Public>,
+    ) -> Result<Transfer> {
+        let new_owner_raw = new_owner.raw_public_key().map_err(Error::KeyPublic)?;
+
+        let mut message = Vec::with_capacity(Hash::LEN + new_owner_raw.len());
+        message.extend_from_slice(self.hash.as_bytes());
+        message.extend_from_slice(&new_owner_raw);
+
+        let signature = Hash::sign(current_owner, &message)?;
+        Ok(Transfer {
+            new_owner: new_owner.clone(),
+            signature,
+        })
+    }
+
+    /// Applies a [Transfer] produced by [Block::transfer], swapping this block's
+    /// [Ownership] to the transfer's new owner once the signature has been
+    /// confirmed to validate under the *current* owner.
+    pub fn apply_transfer(&mut self, transfer: &Transfer) -> Result<()> {
+        let new_owner_raw = transfer.new_owner.raw_public_key().map_err(Error::KeyPublic)?;
+
+        let mut message = Vec::with_capacity(Hash::LEN + new_owner_raw.len());
+        message.extend_from_slice(self.hash.as_bytes());
+        message.extend_from_slice(&new_owner_raw);
+
+        let verified = match &self.ownership {
+            Ownership::Them(pkey) => Hash::verify_signature(pkey, transfer.signature, &message)?,
+            Ownership::Us(pkey) => Hash::verify_signature(pkey, transfer.signature, &message)?,
+            Ownership::Genesis => return Err(Error::GenesisIsNotKey),
+        };
+
+        if !verified {
+            return Err(Error::TransferSignatureMismatch);
+        }
+
+        self.ownership = Ownership::Them(transfer.new_owner.clone());
+        Ok(())
+    }
 }
 
 impl Default for Block {
@@ -118,6 +393,12 @@ impl Default for Block {
             hash: Hash::default(),
             ownership: Ownership::Genesis,
             signature: [0; Hash::SIG_LEN],
+            #[cfg(feature = "pow")]
+            timestamp: 0,
+            #[cfg(feature = "pow")]
+            difficulty: 0,
+            #[cfg(feature = "pow")]
+            nonce: 0,
             data: vec![],
         }
     }
@@ -125,20 +406,137 @@ impl Default for Block {
 
 #[cfg(feature = "serde")]
 impl Serialize for Block {
+    // Encoded as a tuple struct (positional fields, not a name-keyed map) so
+    // every format - self-describing (JSON, YAML, ...) or not (bincode, ...) -
+    // round-trips through the seq-only `BlockVisitor` below.
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_struct("Block", 4 + 1)?;
-        state.serialize_field("pver", &PROTO_VERSION)?; // custom protocol version
-        state.serialize_field("hash", &self.hash)?;
-        state.serialize_field("ownership", &self.ownership)?;
-        state.serialize_field("data", &self.data)?;
+        #[cfg(not(feature = "pow"))]
+        let mut state = serializer.serialize_tuple_struct("Block", 5)?;
+        #[cfg(feature = "pow")]
+        let mut state = serializer.serialize_tuple_struct("Block", 8)?;
+
+        state.serialize_field(&PROTO_VERSION)?; // custom protocol version
+        state.serialize_field(&self.hash)?;
+        state.serialize_field(&self.ownership)?;
+        state.serialize_field(&self.signature[..])?;
+        #[cfg(feature = "pow")]
+        {
+            state.serialize_field(&self.timestamp)?;
+            state.serialize_field(&self.difficulty)?;
+            state.serialize_field(&self.nonce)?;
+        }
+        state.serialize_field(&self.data)?;
         state.end()
     }
 }
 
-// TODO: deserialize
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Block {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BlockVisitor;
+
+        impl<'de> Visitor<'de> for BlockVisitor {
+            type Value = Block;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a serialized Block")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> std::result::Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let pver: u8 = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                if pver != PROTO_VERSION {
+                    return Err(de::Error::custom(format!(
+                        "{}",
+                        Error::BadVersion {
+                            found: pver,
+                            expected: PROTO_VERSION
+                        }
+                    )));
+                }
+
+                let hash: Hash = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let ownership: Ownership = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                // arrays longer than 32 bytes aren't natively (de)serializable, so
+                // the signature travels as a byte vec and gets copied into place
+                let signature_bytes: Vec<u8> = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+                if signature_bytes.len() != Hash::SIG_LEN {
+                    return Err(de::Error::invalid_length(signature_bytes.len(), &self));
+                }
+                let mut signature = [0; Hash::SIG_LEN];
+                signature.copy_from_slice(&signature_bytes);
+
+                #[cfg(feature = "pow")]
+                let timestamp: i64 = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(4, &self))?;
+                #[cfg(feature = "pow")]
+                let difficulty: usize = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(5, &self))?;
+                #[cfg(feature = "pow")]
+                let nonce: u64 = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(6, &self))?;
+
+                #[cfg(not(feature = "pow"))]
+                let data: Vec<u8> = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(4, &self))?;
+                #[cfg(feature = "pow")]
+                let data: Vec<u8> = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(7, &self))?;
+
+                if data.len() > Block::MAX_DATA_LEN {
+                    return Err(de::Error::custom(format!(
+                        "{}",
+                        Error::DataTooLarge {
+                            len: data.len(),
+                            max: Block::MAX_DATA_LEN
+                        }
+                    )));
+                }
+
+                Ok(Block {
+                    hash,
+                    ownership,
+                    signature,
+                    #[cfg(feature = "pow")]
+                    timestamp,
+                    #[cfg(feature = "pow")]
+                    difficulty,
+                    #[cfg(feature = "pow")]
+                    nonce,
+                    data,
+                })
+            }
+        }
+
+        #[cfg(not(feature = "pow"))]
+        const LEN: usize = 5;
+        #[cfg(feature = "pow")]
+        const LEN: usize = 8;
+
+        deserializer.deserialize_tuple_struct("Block", LEN, BlockVisitor)
+    }
+}
 
 /// Contains ownership keys and information for a given block
 #[derive(Debug, Clone)]
@@ -180,12 +578,261 @@ impl Serialize for Ownership {
     where
         S: serde::Serializer,
     {
-        serializer.serialize_bytes(
-            &self
-                .to_raw_public()
-                .map_err(|err| serde::ser::Error::custom(&format!("{}", err)))?[..],
+        match self {
+            // no key material to carry for the genesis block
+            Self::Genesis => serializer.serialize_bytes(&[]),
+            Self::Them(_) | Self::Us(_) => serializer.serialize_bytes(
+                &self
+                    .to_raw_public()
+                    .map_err(|err| serde::ser::Error::custom(format!("{}", err)))?[..],
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Ownership {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct OwnershipVisitor;
+
+        impl<'de> Visitor<'de> for OwnershipVisitor {
+            type Value = Ownership;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("raw ed25519 public key bytes, empty for the genesis block")
+            }
+
+            fn visit_bytes<E>(self, bytes: &[u8]) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                // only public material is ever serialized, so an incoming key always
+                // lands as `Them`, never `Us`; empty or all-zero bytes mean genesis
+                if bytes.is_empty() || bytes.iter().all(|byte| *byte == 0) {
+                    return Ok(Ownership::Genesis);
+                }
+
+                PKey::public_key_from_raw_bytes(bytes, Id::ED25519)
+                    .map(Ownership::Them)
+                    .map_err(|err| de::Error::custom(format!("{}", Error::KeyFromRaw(err))))
+            }
+
+            fn visit_byte_buf<E>(self, bytes: Vec<u8>) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.visit_bytes(&bytes)
+            }
+
+            // self-describing formats (JSON, ...) render `serialize_bytes` as a
+            // plain sequence rather than a byte string, so bytes need collecting
+            // the same way `BlockVisitor` collects `signature`
+            fn visit_seq<A>(self, mut seq: A) -> std::result::Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(byte) = seq.next_element()? {
+                    bytes.push(byte);
+                }
+                self.visit_byte_buf(bytes)
+            }
+        }
+
+        deserializer.deserialize_byte_buf(OwnershipVisitor)
+    }
+}
+
+/// A signed hand-over of a [Block] from its current owner to a new one.
+///
+/// Produced by [Block::transfer] and applied with [Block::apply_transfer].
+#[derive(Debug, Clone)]
+pub struct Transfer {
+    /// Public key of the owner the block is being handed over to.
+    pub new_owner: PKey<Public>,
+    /// Signature, by the current owner, over `(block hash ‖ new owner's raw
+    /// public key bytes)`.
+    pub signature: [u8; Hash::SIG_LEN],
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn block_round_trips_through_a_self_describing_format() {
+        let genesis = Block::default();
+        let block = Block::new(&genesis.hash, "Hello, world!").unwrap();
+
+        let encoded = serde_json::to_vec(&block).unwrap();
+        let decoded: Block = serde_json::from_slice(&encoded).unwrap();
+
+        assert_eq!(decoded.hash, block.hash);
+        assert_eq!(decoded.signature, block.signature);
+        assert_eq!(decoded.data, block.data);
+        assert!(decoded.verify(&genesis.hash).unwrap());
+    }
+
+    #[test]
+    fn deserialize_rejects_mismatched_protocol_version() {
+        let genesis = Block::default();
+        let block = Block::new(&genesis.hash, "Hello, world!").unwrap();
+
+        let mut value = serde_json::to_value(&block).unwrap();
+        value[0] = serde_json::json!(PROTO_VERSION + 1);
+
+        let err = serde_json::from_value::<Block>(value).unwrap_err();
+        assert!(err.to_string().contains("protocol version mismatch"));
+    }
+}
+
+#[cfg(test)]
+mod data_len_tests {
+    use super::*;
+
+    #[test]
+    fn new_rejects_data_over_the_default_max() {
+        let genesis = Block::default();
+        let data = vec![0u8; Block::MAX_DATA_LEN + 1];
+
+        let err = Block::new(&genesis.hash, data).unwrap_err();
+        match err {
+            Error::DataTooLarge { len, max } => {
+                assert_eq!(len, Block::MAX_DATA_LEN + 1);
+                assert_eq!(max, Block::MAX_DATA_LEN);
+            }
+            other => panic!("expected Error::DataTooLarge, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn new_bounded_rejects_data_over_a_custom_max() {
+        let genesis = Block::default();
+        let err = Block::new_bounded(&genesis.hash, vec![0u8; 5], 4).unwrap_err();
+        assert!(matches!(err, Error::DataTooLarge { len: 5, max: 4 }));
+    }
+
+    #[test]
+    fn new_bounded_accepts_data_at_the_limit() {
+        let genesis = Block::default();
+        assert!(Block::new_bounded(&genesis.hash, vec![0u8; 4], 4).is_ok());
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod data_len_deserialize_tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_rejects_data_over_the_max() {
+        let genesis = Block::default();
+        let mut block = Block::new(&genesis.hash, "Hello, world!").unwrap();
+        block.data = vec![0u8; Block::MAX_DATA_LEN + 1];
+
+        let encoded = serde_json::to_vec(&block).unwrap();
+        let err = serde_json::from_slice::<Block>(&encoded).unwrap_err();
+        assert!(err.to_string().contains("exceeding the maximum"));
+    }
+}
+
+#[cfg(test)]
+mod transfer_tests {
+    use super::*;
+    use openssl::pkey::Id;
+
+    #[test]
+    fn transfer_then_apply_changes_ownership() {
+        let genesis = Block::default();
+        let mut block = Block::new(&genesis.hash, "Hello, world!").unwrap();
+
+        let current_owner = match &block.ownership {
+            Ownership::Us(pkey) => pkey.clone(),
+            _ => unreachable!(),
+        };
+        let new_owner_key = PKey::generate_ed25519().unwrap();
+        let new_owner = PKey::public_key_from_raw_bytes(
+            &new_owner_key.raw_public_key().unwrap(),
+            Id::ED25519,
+        )
+        .unwrap();
+
+        let transfer = block.transfer(&current_owner, &new_owner).unwrap();
+        block.apply_transfer(&transfer).unwrap();
+
+        match &block.ownership {
+            Ownership::Them(pkey) => {
+                assert_eq!(
+                    pkey.raw_public_key().unwrap(),
+                    new_owner.raw_public_key().unwrap()
+                );
+            }
+            _ => panic!("expected ownership to land on the new owner"),
+        }
+    }
+
+    #[test]
+    fn apply_transfer_rejects_wrong_signer() {
+        let genesis = Block::default();
+        let mut block = Block::new(&genesis.hash, "Hello, world!").unwrap();
+
+        // signed by an unrelated key, not the block's current owner
+        let impostor = PKey::generate_ed25519().unwrap();
+        let new_owner_key = PKey::generate_ed25519().unwrap();
+        let new_owner = PKey::public_key_from_raw_bytes(
+            &new_owner_key.raw_public_key().unwrap(),
+            Id::ED25519,
         )
+        .unwrap();
+
+        let transfer = block.transfer(&impostor, &new_owner).unwrap();
+        let err = block.apply_transfer(&transfer).unwrap_err();
+        assert!(matches!(err, Error::TransferSignatureMismatch));
+    }
+
+    #[test]
+    fn apply_transfer_rejects_genesis_block() {
+        let genesis = Block::default();
+        let new_owner_key = PKey::generate_ed25519().unwrap();
+        let new_owner = PKey::public_key_from_raw_bytes(
+            &new_owner_key.raw_public_key().unwrap(),
+            Id::ED25519,
+        )
+        .unwrap();
+
+        let impostor = PKey::generate_ed25519().unwrap();
+        let transfer = genesis.transfer(&impostor, &new_owner).unwrap();
+        let mut genesis = genesis;
+        let err = genesis.apply_transfer(&transfer).unwrap_err();
+        assert!(matches!(err, Error::GenesisIsNotKey));
     }
 }
 
-// TODO: deserialize
+#[cfg(all(test, feature = "pow"))]
+mod pow_tests {
+    use super::*;
+
+    #[test]
+    fn mine_rejects_zero_difficulty() {
+        let genesis = Block::default();
+        let err = Block::mine(&genesis.hash, "Hello, world!", 0).unwrap_err();
+        assert!(matches!(err, Error::ZeroDifficulty));
+    }
+
+    #[test]
+    fn mined_block_verifies() {
+        let genesis = Block::default();
+        let block = Block::mine(&genesis.hash, "Hello, world!", 8).unwrap();
+        assert!(block.verify(&genesis.hash).unwrap());
+    }
+
+    #[test]
+    fn mined_block_rejects_tampered_data() {
+        let genesis = Block::default();
+        let mut block = Block::mine(&genesis.hash, "Hello, world!", 8).unwrap();
+        block.data = b"tampered".to_vec();
+        assert!(!block.verify(&genesis.hash).unwrap());
+    }
+}
@@ -0,0 +1,143 @@
+//! Contains [Chain] and implementations
+
+use crate::{error::Error, Block, Hash, Result};
+
+/// A sequence of linked [Block]s, forming a whole blockchain
+///
+/// # Using
+///
+/// You can in high level terms do the following directly to a chain:
+///
+/// - Create a chain seeded with a genesis block: [Chain::default]
+/// - Append a new block of data: [Chain::push]
+/// - Verify every block links correctly: [Chain::verify]
+/// - Check if a hash is already part of the chain: [Chain::is_known]
+/// - Splice in a validated foreign segment: [Chain::extend]
+///
+/// # Example
+///
+/// ```rust
+/// use onft::Chain;
+///
+/// fn main() -> onft::Result<()> {
+///     let mut chain = Chain::default();
+///     chain.push("Hello, world!")?;
+///
+///     if chain.verify()? {
+///         println!("Verified")
+///     } else {
+///         eprintln!("Not verified")
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Chain(Vec<Block>);
+
+impl Default for Chain {
+    /// Creates a new chain seeded with a single genesis block.
+    fn default() -> Self {
+        Self(vec![Block::default()])
+    }
+}
+
+impl Chain {
+    /// Hashes `data` against the current tip of the chain and appends the
+    /// resulting block.
+    pub fn push(&mut self, data: impl Into<Vec<u8>>) -> Result<()> {
+        let block = Block::new(&self.tip().hash, data)?;
+        self.0.push(block);
+        Ok(())
+    }
+
+    /// Walks the whole chain, confirming each block verifies against its
+    /// predecessor's hash.
+    pub fn verify(&self) -> Result<bool> {
+        for pair in self.0.windows(2) {
+            let (previous, current) = (&pair[0], &pair[1]);
+            if !current.verify(&previous.hash)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Checks whether `hash` belongs to a block already present in this chain.
+    pub fn is_known(&self, hash: &Hash) -> bool {
+        self.0.iter().any(|block| &block.hash == hash)
+    }
+
+    /// Validates a foreign chain segment before splicing it onto this chain.
+    ///
+    /// `previous_hash` must be the hash of a block already known to this chain that
+    /// `segment`'s first block was built on top of. Every block in `segment` is
+    /// verified against its predecessor (the previous block in the segment, or
+    /// `previous_hash` for the first one) before anything is appended.
+    pub fn extend(&mut self, previous_hash: &Hash, segment: Vec<Block>) -> Result<()> {
+        if !self.is_known(previous_hash) {
+            return Err(Error::UnknownPreviousHash);
+        }
+
+        let mut previous_hash = previous_hash;
+        for (index, block) in segment.iter().enumerate() {
+            if !block.verify(previous_hash)? {
+                return Err(Error::InvalidSegmentBlock { index });
+            }
+            previous_hash = &block.hash;
+        }
+
+        self.0.extend(segment);
+        Ok(())
+    }
+
+    /// Returns the most recently appended block.
+    fn tip(&self) -> &Block {
+        self.0
+            .last()
+            .expect("chain always contains at least the genesis block")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_then_verify_succeeds() {
+        let mut chain = Chain::default();
+        chain.push("Hello, world!").unwrap();
+        assert!(chain.verify().unwrap());
+    }
+
+    #[test]
+    fn extend_rejects_unknown_previous_hash() {
+        let mut chain = Chain::default();
+        // hash of a block that was never part of `chain`
+        let mut foreign = Chain::default();
+        foreign.push("from elsewhere").unwrap();
+        let unknown_hash = foreign.tip().hash;
+
+        let err = chain.extend(&unknown_hash, vec![]).unwrap_err();
+        assert!(matches!(err, Error::UnknownPreviousHash));
+    }
+
+    #[test]
+    fn extend_rejects_invalid_segment_block() {
+        let mut chain = Chain::default();
+        let mut segment = Chain::default();
+        segment.push("from elsewhere").unwrap();
+        let mut segment_blocks = segment.0[1..].to_vec();
+        segment_blocks[0].data = b"tampered".to_vec();
+
+        let genesis_hash = chain.0[0].hash;
+        let err = chain.extend(&genesis_hash, segment_blocks).unwrap_err();
+        assert!(matches!(err, Error::InvalidSegmentBlock { index: 0 }));
+    }
+
+    #[test]
+    fn is_known_finds_appended_blocks() {
+        let mut chain = Chain::default();
+        chain.push("Hello, world!").unwrap();
+        assert!(chain.is_known(&chain.tip().hash));
+    }
+}
@@ -0,0 +1,118 @@
+//! Contains [Error] and the crate's [Result] alias, alongside the lower-level
+//! signing/verification error wrappers it carries
+
+use std::fmt;
+
+/// Convenience alias for a [Result](std::result::Result) which returns this crate's
+/// [Error]
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors which can arise while creating, signing or verifying a
+/// [Block](crate::Block)
+#[derive(Debug)]
+pub enum Error {
+    /// Attempted to treat the genesis block's [Ownership](crate::block::Ownership) as
+    /// if it carried an owning key.
+    GenesisIsNotKey,
+    /// Failed to read the raw public key bytes out of an
+    /// [Ownership](crate::block::Ownership).
+    KeyPublic(openssl::error::ErrorStack),
+    /// Failed to generate a new keypair for a freshly created block.
+    KeyGen(openssl::error::ErrorStack),
+    /// Failed to compute a digest over a block's previous hash and data.
+    Digest(openssl::error::ErrorStack),
+    /// Failed to reconstruct a public key from raw bytes, usually while
+    /// deserializing.
+    KeyFromRaw(openssl::error::ErrorStack),
+    /// Lower-level failure while signing a block's hash.
+    Signer(SignerError),
+    /// Lower-level failure while verifying a block's signature.
+    Verifier(VerifierError),
+    /// A deserialized block declared a protocol version which doesn't match the
+    /// version this crate supports.
+    BadVersion {
+        /// Version found on the incoming payload.
+        found: u8,
+        /// Version this crate expects.
+        expected: u8,
+    },
+    /// Attempted to import a foreign chain segment on top of a previous hash which
+    /// isn't part of the existing [Chain](crate::Chain).
+    UnknownPreviousHash,
+    /// A block within an imported chain segment failed to verify against its
+    /// predecessor.
+    InvalidSegmentBlock {
+        /// Index of the offending block within the imported segment.
+        index: usize,
+    },
+    /// A [Transfer](crate::Transfer)'s signature doesn't validate under the
+    /// block's current owner, so the hand-over can't be trusted.
+    TransferSignatureMismatch,
+    /// A block's `data` exceeded the maximum permitted length.
+    DataTooLarge {
+        /// Length of the rejected data, in bytes.
+        len: usize,
+        /// Maximum permitted length, in bytes.
+        max: usize,
+    },
+    /// Attempted to [mine](crate::Block::mine) a block with a `difficulty` of
+    /// zero, which isn't a meaningful proof-of-work target and would make the
+    /// resulting block indistinguishable from an un-mined one during
+    /// verification.
+    #[cfg(feature = "pow")]
+    ZeroDifficulty,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::GenesisIsNotKey => write!(f, "genesis block has no owning key"),
+            Self::KeyPublic(err) => write!(f, "could not read raw public key: {}", err),
+            Self::KeyGen(err) => write!(f, "could not generate keypair: {}", err),
+            Self::Digest(err) => write!(f, "could not compute digest: {}", err),
+            Self::KeyFromRaw(err) => write!(f, "could not reconstruct public key: {}", err),
+            Self::Signer(err) => write!(f, "could not sign block: {}", err.0),
+            Self::Verifier(err) => write!(f, "could not verify block: {}", err.0),
+            Self::BadVersion { found, expected } => write!(
+                f,
+                "protocol version mismatch: found {}, expected {}",
+                found, expected
+            ),
+            Self::UnknownPreviousHash => write!(
+                f,
+                "imported segment's previous hash isn't part of this chain"
+            ),
+            Self::InvalidSegmentBlock { index } => write!(
+                f,
+                "block {} of imported segment failed to verify against its predecessor",
+                index
+            ),
+            Self::TransferSignatureMismatch => write!(
+                f,
+                "transfer signature doesn't validate under the block's current owner"
+            ),
+            Self::DataTooLarge { len, max } => write!(
+                f,
+                "block data is {} bytes, exceeding the maximum of {} bytes",
+                len, max
+            ),
+            #[cfg(feature = "pow")]
+            Self::ZeroDifficulty => write!(
+                f,
+                "cannot mine a block with a difficulty of zero, use Block::new instead"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Lower-level error produced while signing a block's hash, wrapped by
+/// [Error::Signer]
+#[derive(Debug)]
+pub struct SignerError(pub(crate) openssl::error::ErrorStack);
+
+/// Lower-level error produced while verifying a block's signature, wrapped by
+/// [Error::Verifier]
+#[derive(Debug)]
+pub struct VerifierError(pub(crate) openssl::error::ErrorStack);
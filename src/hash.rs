@@ -0,0 +1,325 @@
+//! Contains [Hash], [HashAlg] and the digest which links one [Block](crate::Block)
+//! to the next
+
+use crate::error::{Error, Result, SignerError, VerifierError};
+use openssl::hash::{hash, MessageDigest};
+use openssl::pkey::{HasPublic, PKey, PKeyRef, Private};
+use openssl::sign::{Signer, Verifier};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Selects which hashing algorithm is used to digest a block, embedded alongside a
+/// [Hash] so it can later be verified/deserialized with the right algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HashAlg {
+    /// SHA-256 via OpenSSL. The default, requiring no extra feature.
+    #[default]
+    Sha256,
+    /// BLAKE3, a dependency-light, no-OpenSSL 32-byte digest.
+    #[cfg(feature = "blake3")]
+    Blake3,
+}
+
+/// Digest which links a [Block](crate::Block) to the previous block's hash and its
+/// own data, signed by whoever minted the block.
+///
+/// # Example
+///
+/// ```rust
+/// use onft::Hash;
+///
+/// let genesis = Hash::default();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Hash {
+    bytes: [u8; Hash::LEN],
+    alg: HashAlg,
+}
+
+impl<'a> Hash {
+    /// Length in bytes of a digest produced by any of this crate's hashing
+    /// algorithms.
+    pub const LEN: usize = 32;
+    /// Length in bytes of an ed25519 signature.
+    pub const SIG_LEN: usize = 64;
+
+    /// Generates a new keypair and uses it to hash (with the default algorithm) and
+    /// sign `data` on top of `previous_hash`, returning the resulting hash,
+    /// signature and the private key which now owns the block.
+    pub fn new(
+        previous_hash: impl Into<&'a Hash>,
+        data: &[u8],
+    ) -> Result<(Self, [u8; Self::SIG_LEN], PKey<Private>)> {
+        Self::new_with(previous_hash, data, HashAlg::default())
+    }
+
+    /// Like [Hash::new], but lets the caller pick which [HashAlg] digests the
+    /// block.
+    pub fn new_with(
+        previous_hash: impl Into<&'a Hash>,
+        data: &[u8],
+        alg: HashAlg,
+    ) -> Result<(Self, [u8; Self::SIG_LEN], PKey<Private>)> {
+        let pkey = PKey::generate_ed25519().map_err(Error::KeyGen)?;
+        let digest = Self::digest(previous_hash.into(), data, alg)?;
+        let signature = Self::sign(&pkey, &digest.bytes)?;
+        Ok((digest, signature, pkey))
+    }
+
+    /// Verifies that this hash is indeed the digest (under its own embedded
+    /// [HashAlg]) of `previous_hash`/`data` and that `signature` validates against
+    /// `pkey` for that digest.
+    pub fn verify<T: HasPublic>(
+        &self,
+        previous_hash: impl Into<&'a Hash>,
+        signature: [u8; Self::SIG_LEN],
+        data: &[u8],
+        pkey: &PKeyRef<T>,
+    ) -> Result<bool> {
+        let expected = Self::digest(previous_hash.into(), data, self.alg)?;
+        if expected != *self {
+            return Ok(false);
+        }
+
+        Self::verify_signature(pkey, signature, &self.bytes)
+    }
+
+    /// Returns the raw digest bytes, used when a message needs to be signed or
+    /// verified over this exact hash (for example a [Transfer](crate::Transfer)).
+    pub(crate) fn as_bytes(&self) -> &[u8; Self::LEN] {
+        &self.bytes
+    }
+
+    /// Signs `message` with `pkey`, returning the raw fixed-length signature.
+    pub(crate) fn sign(pkey: &PKey<Private>, message: &[u8]) -> Result<[u8; Self::SIG_LEN]> {
+        let mut signer =
+            Signer::new_without_digest(pkey).map_err(|err| Error::Signer(SignerError(err)))?;
+        let signature = signer
+            .sign_oneshot_to_vec(message)
+            .map_err(|err| Error::Signer(SignerError(err)))?;
+
+        let mut out = [0; Self::SIG_LEN];
+        out.copy_from_slice(&signature);
+        Ok(out)
+    }
+
+    /// Verifies that `signature` validates against `pkey` for `message`.
+    pub(crate) fn verify_signature<T: HasPublic>(
+        pkey: &PKeyRef<T>,
+        signature: [u8; Self::SIG_LEN],
+        message: &[u8],
+    ) -> Result<bool> {
+        let mut verifier = Verifier::new_without_digest(pkey)
+            .map_err(|err| Error::Verifier(VerifierError(err)))?;
+        verifier
+            .verify_oneshot(&signature, message)
+            .map_err(|err| Error::Verifier(VerifierError(err)))
+    }
+
+    /// Computes the digest of `previous_hash` concatenated with `data` using
+    /// `alg`.
+    fn digest(previous_hash: &Hash, data: &[u8], alg: HashAlg) -> Result<Self> {
+        let bytes = match alg {
+            HashAlg::Sha256 => {
+                let mut buf = Vec::with_capacity(Self::LEN + data.len());
+                buf.extend_from_slice(&previous_hash.bytes);
+                buf.extend_from_slice(data);
+
+                let digest = hash(MessageDigest::sha256(), &buf).map_err(Error::Digest)?;
+                let mut out = [0; Self::LEN];
+                out.copy_from_slice(&digest);
+                out
+            }
+            #[cfg(feature = "blake3")]
+            HashAlg::Blake3 => {
+                let mut hasher = blake3::Hasher::new();
+                hasher.update(&previous_hash.bytes);
+                hasher.update(data);
+                *hasher.finalize().as_bytes()
+            }
+        };
+        Ok(Self { bytes, alg })
+    }
+
+    /// Generates a new keypair and searches for a `nonce` such that the digest of
+    /// `(previous_hash ‖ timestamp ‖ data ‖ nonce)` has at least `difficulty`
+    /// leading zero bits, signing the winning digest once found.
+    ///
+    /// Mining always hashes with [HashAlg::Sha256]; the proof-of-work digest is a
+    /// distinct format from the plain block digest. `difficulty` must be
+    /// non-zero (rejected with [Error::ZeroDifficulty]), since a zero
+    /// difficulty would be indistinguishable from a block that was never mined
+    /// at all once `verify` only checks the PoW digest for mined blocks.
+    #[cfg(feature = "pow")]
+    pub(crate) fn mine(
+        previous_hash: impl Into<&'a Hash>,
+        timestamp: i64,
+        data: &[u8],
+        difficulty: usize,
+    ) -> Result<(Self, [u8; Self::SIG_LEN], u64, PKey<Private>)> {
+        if difficulty == 0 {
+            return Err(Error::ZeroDifficulty);
+        }
+
+        let previous_hash = previous_hash.into();
+        let pkey = PKey::generate_ed25519().map_err(Error::KeyGen)?;
+
+        let mut nonce: u64 = 0;
+        let digest = loop {
+            let candidate = Self::digest_pow(previous_hash, timestamp, data, nonce)?;
+            if candidate.leading_zero_bits() >= difficulty {
+                break candidate;
+            }
+            nonce += 1;
+        };
+
+        let signature = Self::sign(&pkey, &digest.bytes)?;
+        Ok((digest, signature, nonce, pkey))
+    }
+
+    /// Verifies that this hash is the proof-of-work digest of
+    /// `previous_hash`/`timestamp`/`data`/`nonce`, that it satisfies `difficulty`,
+    /// and that `signature` validates against `pkey` for that digest.
+    #[cfg(feature = "pow")]
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn verify_pow<T: HasPublic>(
+        &self,
+        previous_hash: impl Into<&'a Hash>,
+        timestamp: i64,
+        data: &[u8],
+        nonce: u64,
+        difficulty: usize,
+        signature: [u8; Self::SIG_LEN],
+        pkey: &PKeyRef<T>,
+    ) -> Result<bool> {
+        let expected = Self::digest_pow(previous_hash.into(), timestamp, data, nonce)?;
+        if expected != *self || expected.leading_zero_bits() < difficulty {
+            return Ok(false);
+        }
+
+        Self::verify_signature(pkey, signature, &self.bytes)
+    }
+
+    /// Computes the digest of `previous_hash ‖ timestamp ‖ data ‖ nonce`.
+    #[cfg(feature = "pow")]
+    fn digest_pow(previous_hash: &Hash, timestamp: i64, data: &[u8], nonce: u64) -> Result<Self> {
+        let mut buf = Vec::with_capacity(Self::LEN + 8 + data.len() + 8);
+        buf.extend_from_slice(&previous_hash.bytes);
+        buf.extend_from_slice(&timestamp.to_be_bytes());
+        buf.extend_from_slice(data);
+        buf.extend_from_slice(&nonce.to_be_bytes());
+
+        let digest = hash(MessageDigest::sha256(), &buf).map_err(Error::Digest)?;
+        let mut out = [0; Self::LEN];
+        out.copy_from_slice(&digest);
+        Ok(Self {
+            bytes: out,
+            alg: HashAlg::Sha256,
+        })
+    }
+
+    /// Counts this hash's leading zero bits, MSB-first, stopping at the first set
+    /// bit.
+    #[cfg(feature = "pow")]
+    fn leading_zero_bits(&self) -> usize {
+        let mut count = 0;
+        for byte in &self.bytes {
+            if *byte == 0 {
+                count += 8;
+                continue;
+            }
+            count += byte.leading_zeros() as usize;
+            break;
+        }
+        count
+    }
+}
+
+#[cfg(all(test, feature = "pow"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mine_rejects_zero_difficulty() {
+        let genesis = Hash::default();
+        let err = Hash::mine(&genesis, 0, b"data", 0).unwrap_err();
+        assert!(matches!(err, Error::ZeroDifficulty));
+    }
+
+    #[test]
+    fn mine_then_verify_pow_roundtrips() {
+        let genesis = Hash::default();
+        let (hash, signature, nonce, pkey) = Hash::mine(&genesis, 1_700_000_000, b"data", 8).unwrap();
+
+        let verified = hash
+            .verify_pow(&genesis, 1_700_000_000, b"data", nonce, 8, signature, &pkey)
+            .unwrap();
+        assert!(verified);
+    }
+
+    #[test]
+    fn verify_pow_rejects_tampered_data() {
+        let genesis = Hash::default();
+        let (hash, signature, nonce, pkey) = Hash::mine(&genesis, 1_700_000_000, b"data", 8).unwrap();
+
+        let verified = hash
+            .verify_pow(
+                &genesis,
+                1_700_000_000,
+                b"tampered",
+                nonce,
+                8,
+                signature,
+                &pkey,
+            )
+            .unwrap();
+        assert!(!verified);
+    }
+
+    #[test]
+    fn verify_pow_rejects_tampered_nonce() {
+        let genesis = Hash::default();
+        let (hash, signature, nonce, pkey) = Hash::mine(&genesis, 1_700_000_000, b"data", 8).unwrap();
+
+        let verified = hash
+            .verify_pow(
+                &genesis,
+                1_700_000_000,
+                b"data",
+                nonce.wrapping_add(1),
+                8,
+                signature,
+                &pkey,
+            )
+            .unwrap();
+        assert!(!verified);
+    }
+}
+
+#[cfg(all(test, feature = "blake3"))]
+mod blake3_tests {
+    use super::*;
+
+    #[test]
+    fn blake3_digest_round_trips() {
+        let genesis = Hash::default();
+        let (hash, signature, pkey) = Hash::new_with(&genesis, b"data", HashAlg::Blake3).unwrap();
+
+        assert_eq!(hash.alg, HashAlg::Blake3);
+        assert!(hash
+            .verify(&genesis, signature, b"data", &pkey)
+            .unwrap());
+    }
+
+    #[test]
+    fn blake3_digest_rejects_tampered_data() {
+        let genesis = Hash::default();
+        let (hash, signature, pkey) = Hash::new_with(&genesis, b"data", HashAlg::Blake3).unwrap();
+
+        assert!(!hash
+            .verify(&genesis, signature, b"tampered", &pkey)
+            .unwrap());
+    }
+}